@@ -0,0 +1,92 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `const fn` free-function mirror of the pointer and slice operations.
+//!
+//! The trait methods elsewhere in this crate cannot be called from `const fn` or in
+//! the initialization of `const`/`static` items. These free functions provide the
+//! same safe-arithmetic and copy operations in a form usable during const
+//! evaluation, so const generic data structures and compile-time tables can do
+//! pointer arithmetic and byte copies at compile time.
+//!
+//! Unlike the rest of the crate, which builds on the old `#![feature(raw)]` /
+//! `#![feature(nonzero)]` nightlies, this module requires a toolchain on which the
+//! underlying `core::ptr` operations are themselves `const fn` — a `const fn` may
+//! only call other `const fn`. The relevant stabilizations landed in Rust 1.85
+//! (const `ptr::read`/`write`/`copy`/`copy_nonoverlapping`/`swap`/`replace`/
+//! `write_bytes` and the const `<*const T>::add`/`sub` methods), so **1.85 is the
+//! minimum toolchain for `konst`**. See `test::const_eval_user` for a compile-time
+//! user that exercises the mirror during const evaluation.
+
+use std::ptr;
+
+/// Calculates the offset from a pointer by addition. `count` is in units of T.
+pub const unsafe fn add<T>(ptr: *const T, count: usize) -> *const T {
+    ptr.add(count)
+}
+
+/// Calculates the offset from a pointer by subtraction. `count` is in units of T.
+pub const unsafe fn sub<T>(ptr: *const T, count: usize) -> *const T {
+    ptr.sub(count)
+}
+
+/// Reads the value from `src` and returns it.
+pub const unsafe fn read<T>(src: *const T) -> T {
+    ptr::read(src)
+}
+
+/// Overwrites the memory location `dst` with `src` without reading or dropping the
+/// old value.
+pub const unsafe fn write<T>(dst: *mut T, src: T) {
+    ptr::write(dst, src)
+}
+
+/// Copies `count` elements from `src` to `dst`, which *may* overlap.
+pub const unsafe fn copy<T>(src: *const T, dst: *mut T, count: usize) {
+    ptr::copy(src, dst, count)
+}
+
+/// Copies `count` elements from `src` to `dst`, which *do not* overlap.
+pub const unsafe fn copy_nonoverlapping<T>(src: *const T, dst: *mut T, count: usize) {
+    ptr::copy_nonoverlapping(src, dst, count)
+}
+
+/// Swaps the values at `x` and `y`, which may point to the same address.
+pub const unsafe fn swap<T>(x: *mut T, y: *mut T) {
+    ptr::swap(x, y)
+}
+
+/// Replaces the value at `dst` with `src`, returning the old value.
+pub const unsafe fn replace<T>(dst: *mut T, src: T) -> T {
+    ptr::replace(dst, src)
+}
+
+/// Sets `count` elements of `T` starting at `dst` to the given byte pattern.
+pub const unsafe fn write_bytes<T>(dst: *mut T, byte: u8, count: usize) {
+    ptr::write_bytes(dst, byte, count)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn const_eval_user() {
+        static DATA: [u8; 4] = [1, 2, 3, 4];
+        // Computed during const evaluation — this is the proof the mirror is usable
+        // from a `const` initializer, not just at runtime.
+        const P: *const u8 = unsafe { add(DATA.as_ptr(), 1) };
+        unsafe {
+            assert_eq!(read(P), 2);
+            assert_eq!(read(sub(P, 1)), 1);
+        }
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::nonzero::NonZero;
+
+use rawptr::{RawPtrExt, RawMutPtrExt};
+
+/// A wrapper around a raw `*mut T` that is guaranteed never to be null.
+///
+/// Because the pointer is stored as a `NonZero`, the null value is reserved as a
+/// niche: `Option<NonNullPtr<T>>` is the same size as a bare pointer. This lets
+/// downstream collection and FFI types that always hold a non-null pointer (buffer
+/// heads, `alloc` results) shrink their `Option<ptr>` fields while still reusing all
+/// the arithmetic defined by `RawPtrExt`/`RawMutPtrExt`.
+pub struct NonNullPtr<T> {
+    ptr: NonZero<*mut T>,
+}
+
+impl<T> Copy for NonNullPtr<T> {}
+
+impl<T> Clone for NonNullPtr<T> {
+    fn clone(&self) -> NonNullPtr<T> {
+        *self
+    }
+}
+
+impl<T> NonNullPtr<T> {
+    /// Creates a `NonNullPtr` if `ptr` is non-null, otherwise returns `None`.
+    pub fn new(ptr: *mut T) -> Option<NonNullPtr<T>> {
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { NonNullPtr::new_unchecked(ptr) })
+        }
+    }
+
+    /// Creates a `NonNullPtr` without checking that `ptr` is non-null.
+    ///
+    /// The caller must guarantee that `ptr` is not null; violating this invalidates
+    /// the niche optimization and is undefined behavior.
+    pub unsafe fn new_unchecked(ptr: *mut T) -> NonNullPtr<T> {
+        NonNullPtr { ptr: NonZero::new(ptr) }
+    }
+
+    /// Returns the wrapped pointer as a plain `*mut T`.
+    pub fn as_ptr(self) -> *mut T {
+        *self.ptr
+    }
+}
+
+impl<T> RawPtrExt<T> for NonNullPtr<T> {
+    fn as_raw_slice(self, len: usize) -> *const [T] {
+        self.as_ptr().as_raw_slice(len)
+    }
+
+    unsafe fn as_slice<'a>(self, len: usize) -> &'a [T] {
+        self.as_ptr().as_slice(len)
+    }
+
+    unsafe fn read(self) -> T {
+        self.as_ptr().read()
+    }
+
+    unsafe fn add(self, count: usize) -> Self {
+        NonNullPtr::new_unchecked(self.as_ptr().add(count))
+    }
+
+    unsafe fn sub(self, count: usize) -> Self {
+        NonNullPtr::new_unchecked(self.as_ptr().sub(count))
+    }
+
+    unsafe fn copy(self, dest: *mut T, count: usize) {
+        self.as_ptr().copy(dest, count);
+    }
+
+    unsafe fn copy_nonoverlapping(self, dest: *mut T, count: usize) {
+        self.as_ptr().copy_nonoverlapping(dest, count);
+    }
+}
+
+impl<T> RawMutPtrExt<T> for NonNullPtr<T> {
+    fn as_raw_mut_slice(self, len: usize) -> *mut [T] {
+        self.as_ptr().as_raw_mut_slice(len)
+    }
+
+    unsafe fn as_mut_slice<'a>(self, len: usize) -> &'a mut [T] {
+        self.as_ptr().as_mut_slice(len)
+    }
+
+    unsafe fn write(self, src: T) {
+        self.as_ptr().write(src);
+    }
+
+    unsafe fn write_bytes(self, byte: u8, count: usize) {
+        self.as_ptr().write_bytes(byte, count);
+    }
+
+    unsafe fn swap(self, y: *mut T) {
+        self.as_ptr().swap(y);
+    }
+
+    unsafe fn replace(self, src: T) -> T {
+        self.as_ptr().replace(src)
+    }
+
+    unsafe fn copy_either(self, dest: *mut T, count: usize) {
+        self.as_ptr().copy_either(dest, count);
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::mem::size_of;
+    use std::ptr;
+
+    #[test]
+    fn test_niche() {
+        // The reserved null value lets `Option` reuse the pointer's layout.
+        assert_eq!(size_of::<Option<NonNullPtr<u8>>>(), size_of::<*mut u8>());
+    }
+
+    #[test]
+    fn test_new_round_trip() {
+        let mut v = 7u8;
+        let p = &mut v as *mut u8;
+        let nn = NonNullPtr::new(p).unwrap();
+        assert_eq!(nn.as_ptr(), p);
+
+        assert!(NonNullPtr::new(ptr::null_mut::<u8>()).is_none());
+
+        unsafe {
+            let nn = NonNullPtr::new_unchecked(p);
+            assert_eq!(nn.as_ptr(), p);
+        }
+    }
+}
@@ -13,6 +13,35 @@ use std::ptr;
 use std::raw::Slice;
 use rawslice::{RawSlice, RawMutSlice};
 
+/// Returns the absolute difference between two addresses.
+fn abs_difference(a: usize, b: usize) -> usize {
+    if a > b { a - b } else { b - a }
+}
+
+/// Returns `true` if `ptr` is non-null and correctly aligned for `T`. Used by the
+/// debug-only guards to turn a misuse into an immediate panic instead of silent UB.
+fn is_aligned_and_not_null<T>(ptr: *const T) -> bool {
+    !ptr.is_null() && (ptr as usize) % mem::align_of::<T>() == 0
+}
+
+/// Returns `true` if the `count`-element regions starting at `src` and `dst` do not
+/// overlap, computed without forming any out-of-bounds pointer. The regions are
+/// disjoint iff the distance between their bases is at least `count * size_of::<T>()`.
+fn is_nonoverlapping<T>(src: *const T, dst: *const T, count: usize) -> bool {
+    let size = mem::size_of::<T>().checked_mul(count).unwrap();
+    abs_difference(src as usize, dst as usize) >= size
+}
+
+/// Like `is_nonoverlapping`, but for the release-time `copy_either` dispatch where a
+/// panic would be a regression over the bare `ptr::copy*` calls. Overflow in
+/// `count * size_of::<T>()` saturates, which conservatively reports the regions as
+/// overlapping — an overflowing copy is already UB-sized, so falling back to the
+/// `memmove` path is the safe choice.
+fn regions_disjoint<T>(src: *const T, dst: *const T, count: usize) -> bool {
+    let size = mem::size_of::<T>().saturating_mul(count);
+    abs_difference(src as usize, dst as usize) >= size
+}
+
 /// Extension trait for convenience methods on raw pointers
 pub trait RawPtrExt<T> {
     /// Converts the pointer into a raw slice.
@@ -70,6 +99,13 @@ pub trait RawMutPtrExt<T> {
     /// Replace the value of the pointer, returning the old value. This is simply
     /// a convenience for calling `mem::replace` with a raw pointer.
     unsafe fn replace(self, src: T) -> T;
+
+    /// Copies `count` elements from `self` to `dest`, selecting the non-overlapping
+    /// fast path when the two regions are provably disjoint and falling back to the
+    /// overlapping path otherwise. Uses the same disjointness test as the debug
+    /// guards, so callers who don't statically know whether their ranges alias get
+    /// `memcpy` speed whenever it is actually safe without risking a wrong guess.
+    unsafe fn copy_either(self, dest: *mut T, count: usize);
 }
 
 impl<T> RawPtrExt<T> for *const T {
@@ -87,6 +123,7 @@ impl<T> RawPtrExt<T> for *const T {
     }
 
     unsafe fn read(self) -> T {
+        debug_assert!(is_aligned_and_not_null(self), "read from a null or misaligned pointer");
         ptr::read(self)
     }
 
@@ -99,10 +136,15 @@ impl<T> RawPtrExt<T> for *const T {
     }
 
     unsafe fn copy(self, dest: *mut T, count: usize) {
+        debug_assert!(is_aligned_and_not_null(self), "copy from a null or misaligned pointer");
+        debug_assert!(is_aligned_and_not_null(dest as *const T), "copy to a null or misaligned pointer");
         ptr::copy(self, dest, count);
     }
 
     unsafe fn copy_nonoverlapping(self, dest: *mut T, count: usize) {
+        debug_assert!(is_aligned_and_not_null(self), "copy_nonoverlapping from a null or misaligned pointer");
+        debug_assert!(is_aligned_and_not_null(dest as *const T), "copy_nonoverlapping to a null or misaligned pointer");
+        debug_assert!(is_nonoverlapping(self, dest as *const T, count), "copy_nonoverlapping with overlapping regions");
         ptr::copy_nonoverlapping(self, dest, count);
     }
 }
@@ -117,6 +159,7 @@ impl<T> RawPtrExt<T> for *mut T {
     }
 
     unsafe fn read(self) -> T {
+        debug_assert!(is_aligned_and_not_null(self as *const T), "read from a null or misaligned pointer");
         ptr::read(self as *const T)
     }
 
@@ -129,10 +172,15 @@ impl<T> RawPtrExt<T> for *mut T {
     }
 
     unsafe fn copy(self, dest: *mut T, count: usize) {
+        debug_assert!(is_aligned_and_not_null(self as *const T), "copy from a null or misaligned pointer");
+        debug_assert!(is_aligned_and_not_null(dest as *const T), "copy to a null or misaligned pointer");
         ptr::copy(self, dest, count);
     }
 
     unsafe fn copy_nonoverlapping(self, dest: *mut T, count: usize) {
+        debug_assert!(is_aligned_and_not_null(self as *const T), "copy_nonoverlapping from a null or misaligned pointer");
+        debug_assert!(is_aligned_and_not_null(dest as *const T), "copy_nonoverlapping to a null or misaligned pointer");
+        debug_assert!(is_nonoverlapping(self as *const T, dest as *const T, count), "copy_nonoverlapping with overlapping regions");
         ptr::copy_nonoverlapping(self, dest, count);
     }
 }
@@ -152,6 +200,7 @@ impl<T> RawMutPtrExt<T> for *mut T {
     }
 
     unsafe fn write(self, src: T) {
+        debug_assert!(is_aligned_and_not_null(self as *const T), "write to a null or misaligned pointer");
         ptr::write(self, src);
     }
 
@@ -166,6 +215,14 @@ impl<T> RawMutPtrExt<T> for *mut T {
     unsafe fn replace(self, src: T) -> T {
         ptr::replace(self, src)
     }
+
+    unsafe fn copy_either(self, dest: *mut T, count: usize) {
+        if regions_disjoint(self as *const T, dest as *const T, count) {
+            self.copy_nonoverlapping(dest, count);
+        } else {
+            self.copy(dest, count);
+        }
+    }
 }
 
 
@@ -218,6 +275,25 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_copy_either() {
+        unsafe {
+            // Overlapping source and destination must take the memmove path.
+            let mut x = [1,2,3,4];
+            let xptr = x.as_mut_ptr();
+            xptr.add(1).copy_either(xptr, 2);
+            assert_eq!(x, [2,3,3,4]);
+
+            // Disjoint regions take the copy_nonoverlapping path.
+            let mut x = [1,2,3,4];
+            let y = [5,6,7,8];
+            let xptr = x.as_mut_ptr();
+            let yptr = y.as_ptr();
+            (yptr as *mut i32).copy_either(xptr, 4);
+            assert_eq!(x, y);
+        }
+    }
+
     #[test]
     fn test_swap_replace() {
         unsafe {
@@ -31,9 +31,14 @@ pub trait RawSlice<T>: Copy + Sized {
         self.as_ptr().add(index).read()
     }
 
+    /// Computes the address of the element at the given index using pointer
+    /// arithmetic only, without ever forming an intermediate reference. Safe to use
+    /// over uninitialized, partially-initialized, packed, or aliased backing memory.
+    unsafe fn get_raw(self, index: usize) -> *const T;
+
     /// Gets a reference to the element at the given index.
     unsafe fn get<'a>(self, index: usize) -> &'a T {
-        &*self.as_ptr().add(index)
+        &*self.get_raw(index)
     }
 
     /// Gets a subslice of this one.
@@ -78,6 +83,17 @@ pub trait RawMutSlice<T> : RawSlice<T> + Sized {
     /// not consider the target's length.
     unsafe fn copy_nonoverlapping(self, from: *const[T]);
 
+    /// Copies the contents of the given rawslice into this one, inspecting the two
+    /// regions at runtime and taking the non-overlapping fast path when they are
+    /// provably disjoint. Uses `from.len()` to determine the length of the copied
+    /// data, but does not consider the target's length.
+    unsafe fn copy_either(self, from: *const[T]);
+
+    /// Computes the mutable address of the element at the given index using pointer
+    /// arithmetic only, without ever forming an intermediate reference. Appropriate
+    /// for writing into uninitialized cells or addressing inside packed layouts.
+    unsafe fn get_mut_raw(self, index: usize) -> *mut T;
+
     /// Gets a mutable reference to the value at the given index.
     unsafe fn get_mut<'a>(self, index: usize) -> &'a mut T;
 }
@@ -109,6 +125,10 @@ impl<T> RawSlice<T> for *const [T] {
         &*self
     }
 
+    unsafe fn get_raw(self, index: usize) -> *const T {
+        (self as *const T).add(index)
+    }
+
     unsafe fn slice(self, from: usize, to: usize) -> *const [T] {
         self.as_ptr().add(from).as_raw_slice(to - from)
     }
@@ -119,6 +139,10 @@ impl<T> RawSlice<T> for *mut [T] {
         &*self
     }
 
+    unsafe fn get_raw(self, index: usize) -> *const T {
+        (self as *mut T as *const T).add(index)
+    }
+
     unsafe fn slice(self, from: usize, to: usize) -> *mut [T] {
         self.as_mut_ptr().add(from).as_raw_mut_slice(to - from)
     }
@@ -150,7 +174,82 @@ impl<T> RawMutSlice<T> for *mut [T] {
         from.as_ptr().copy_nonoverlapping(self.as_mut_ptr(), from.len());
     }
 
+    unsafe fn copy_either(self, from: *const[T]) {
+        (from.as_ptr() as *mut T).copy_either(self.as_mut_ptr(), from.len());
+    }
+
+    unsafe fn get_mut_raw(self, index: usize) -> *mut T {
+        (self as *mut T).add(index)
+    }
+
     unsafe fn get_mut<'a>(self, index: usize) -> &'a mut T {
-        &mut *self.as_mut_ptr().add(index)
+        &mut *self.get_mut_raw(index)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::mem::MaybeUninit;
+
+    #[test]
+    fn test_get_raw() {
+        unsafe {
+            let x = [1,2,3,4];
+            let rs: &[i32] = &x;
+            let rs = rs.as_raw();
+            // The computed address matches the old `&*...add(index)` path exactly.
+            assert_eq!(rs.get_raw(2), &x[2] as *const i32);
+            assert_eq!(*rs.get_raw(2), 3);
+        }
+    }
+
+    #[test]
+    fn test_get_mut_raw() {
+        unsafe {
+            let mut x = [1,2,3,4];
+            let rs = (&mut x[..]).as_mut_raw();
+            let addr = rs.get_mut_raw(1);
+            assert_eq!(addr, &mut x[1] as *mut i32);
+            *addr = 9;
+            assert_eq!(x[1], 9);
+        }
+    }
+
+    #[test]
+    fn test_get_mut_raw_uninit() {
+        unsafe {
+            // get_mut_raw never forms a reference, so it can address and initialize
+            // cells of an uninitialized backing region.
+            let mut buf: [MaybeUninit<i32>; 3] = [MaybeUninit::uninit(); 3];
+            let rs = (&mut buf[..]).as_mut_raw();
+            for i in 0..3 {
+                rs.get_mut_raw(i).write(MaybeUninit::new(i as i32 + 1));
+            }
+            assert_eq!(buf[0].assume_init(), 1);
+            assert_eq!(buf[1].assume_init(), 2);
+            assert_eq!(buf[2].assume_init(), 3);
+        }
+    }
+
+    #[test]
+    fn test_copy_either() {
+        unsafe {
+            // Disjoint regions.
+            let mut x = [0,0,0,0];
+            let y = [5,6,7,8];
+            let dst = (&mut x[..]).as_mut_raw();
+            let src = (&y[..]).as_raw();
+            dst.copy_either(src);
+            assert_eq!(x, [5,6,7,8]);
+
+            // Overlapping regions: copy x[1..3] down over x[0..].
+            let mut x = [1,2,3,4];
+            let src = (&x[1..3]).as_raw();
+            let dst = (&mut x[..]).as_mut_raw();
+            dst.copy_either(src);
+            assert_eq!(x, [2,3,3,4]);
+        }
     }
 }